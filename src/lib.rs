@@ -1,12 +1,16 @@
 #[allow(warnings)]
 mod bindings;
 use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 
 use bindings::{
     exports::supabase::wrappers::routines::Guest,
     supabase::wrappers::{
         http, time,
-        types::{Cell, Context, FdwError, FdwResult, ImportForeignSchemaStmt, OptionsType, Row, TypeOid},
+        types::{
+            Cell, Context, FdwError, FdwResult, ImportForeignSchemaStmt, ImportForeignSchemaType,
+            OptionsType, Row, TypeOid,
+        },
         utils,
     },
 };
@@ -16,7 +20,145 @@ struct LinearFdw {
     base_url: String,
     src_rows: Vec<JsonValue>,
     src_idx: usize,
-    api_key: String,
+    // The full `authorization` header value: a raw personal API key, or a `Bearer <token>`
+    // value when `token_type 'oauth'` is configured.
+    auth_header: String,
+    // `Linear-Actor` header value for app-authored writes under an OAuth app token.
+    actor_header: Option<String>,
+    modify_object: String,
+    modify_rowid_col: String,
+    max_retries: u32,
+    // Incremental sync state (`sync_mode 'incremental'`), keyed by the table's `object`
+    // option. `sync_watermarks` holds the max `updatedAt` committed so far; `sync_seen_ids`
+    // holds the ids already delivered at that exact timestamp, so the next scan's `gte`
+    // filter can re-fetch the boundary without re-emitting rows it already returned.
+    //
+    // This only survives as long as the wasm instance does, i.e. across repeated scans
+    // within one Postgres backend, not across separate connections or a server restart:
+    // the host gives this FDW no binding for writing to a durable, externally-visible
+    // state table, so there is nowhere else to put it. `init_instance` is deliberately
+    // idempotent (see below) so that the `init()` issued on every query doesn't wipe it.
+    sync_watermarks: HashMap<String, String>,
+    sync_seen_ids: HashMap<String, Vec<String>>,
+    // Staged by `begin_scan` once every page has been consumed; `end_scan` commits it into
+    // `sync_watermarks`/`sync_seen_ids` so an interrupted scan never advances the watermark.
+    sync_pending: Option<(String, String, Vec<String>)>,
+}
+
+// Escapes a value interpolated into a GraphQL string literal (`"..."`). Needed anywhere
+// free text (titles, descriptions, filter values) is spliced into a query or mutation
+// body by hand rather than passed as a variable.
+fn escape_graphql_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// Retries a POST request on HTTP 429/5xx with exponential backoff and jitter, honoring
+// the `Retry-After` header when Linear sends one. `build_req` is called again on every
+// attempt since `http::Request` carries an owned, one-shot body.
+fn post_with_retry<F: Fn() -> http::Request>(build_req: F, max_retries: u32) -> Result<http::Response, FdwError> {
+    const BASE_DELAY_MS: u64 = 500;
+    const MAX_DELAY_MS: u64 = 30_000;
+
+    let mut attempt = 0;
+    loop {
+        let req = build_req();
+        let resp = http::post(&req)?;
+
+        if resp.status_code == 429 || resp.status_code >= 500 {
+            if attempt >= max_retries {
+                return Err(format!(
+                    "Request failed after {} retries (status {}): {}",
+                    max_retries, resp.status_code, resp.body
+                ));
+            }
+
+            let retry_after_ms = resp
+                .headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case("retry-after"))
+                .and_then(|(_, v)| v.parse::<u64>().ok())
+                .map(|secs| secs * 1000);
+
+            let delay_ms = retry_after_ms.unwrap_or_else(|| {
+                let backoff = BASE_DELAY_MS.saturating_mul(1u64 << attempt.min(6)).min(MAX_DELAY_MS);
+                let jitter = (attempt as u64 * 137) % 250;
+                (backoff + jitter).min(MAX_DELAY_MS)
+            });
+
+            utils::report_info(&format!(
+                "Linear request throttled (status {}), retrying in {}ms (attempt {}/{})",
+                resp.status_code,
+                delay_ms,
+                attempt + 1,
+                max_retries
+            ));
+            time::sleep(delay_ms);
+            attempt += 1;
+            continue;
+        }
+
+        if let Some((_, remaining)) = resp
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("x-ratelimit-requests-remaining"))
+        {
+            utils::report_info(&format!("Linear rate limit requests remaining: {}", remaining));
+        }
+
+        return Ok(resp);
+    }
+}
+
+// Exercises an OAuth2 access token with a minimal `{ viewer { id } }` query so a token
+// that's missing, revoked, or lacking the scopes Linear requires to authenticate at all
+// is reported here, at `ForeignServer` setup, rather than failing every subsequent scan.
+fn validate_oauth_credential(base_url: &str, token: &str) -> FdwResult {
+    let resp = http::post(&http::Request {
+        method: http::Method::Post,
+        url: base_url.to_owned(),
+        headers: vec![
+            ("content-type".to_owned(), "application/json".to_owned()),
+            ("authorization".to_owned(), format!("Bearer {}", token)),
+        ],
+        body: serde_json::json!({ "query": "{ viewer { id } }" }).to_string(),
+    })?;
+
+    if resp.status_code == 401 || resp.status_code == 403 {
+        return Err(format!(
+            "OAuth token rejected (status {}): it is missing required scopes, expired, or revoked",
+            resp.status_code
+        ));
+    }
+    if resp.status_code != 200 {
+        return Err(format!("Failed to validate OAuth token: {}", resp.body));
+    }
+
+    let resp_json: JsonValue = serde_json::from_str(&resp.body)
+        .map_err(|e| format!("Failed to parse OAuth validation response: {}", e))?;
+    if let Some(errors) = resp_json.get("errors").and_then(|e| e.as_array()) {
+        if !errors.is_empty() {
+            return Err(format!(
+                "OAuth token validation failed: {}",
+                serde_json::to_string(errors).unwrap_or_default()
+            ));
+        }
+    }
+    if resp_json.pointer("/data/viewer/id").and_then(|v| v.as_str()).is_none() {
+        return Err("OAuth token validation failed: could not resolve the authenticated viewer".to_owned());
+    }
+
+    Ok(())
 }
 
 // pointer for the static FDW instance
@@ -24,10 +166,15 @@ static mut INSTANCE: *mut LinearFdw = std::ptr::null_mut::<LinearFdw>();
 
 impl LinearFdw {
     // initialise FDW instance
+    // Idempotent: `init()` runs once per query, but a fresh `Self::default()` on every
+    // call would wipe `sync_watermarks`/`sync_seen_ids` between queries in the same
+    // backend, defeating incremental sync entirely. Only allocate the instance the first
+    // time; `init()` still re-applies the server options on top of it every time.
     fn init_instance() {
-        let instance = Self::default();
         unsafe {
-            INSTANCE = Box::leak(Box::new(instance));
+            if INSTANCE.is_null() {
+                INSTANCE = Box::leak(Box::new(Self::default()));
+            }
         }
     }
 
@@ -49,24 +196,52 @@ impl Guest for LinearFdw {
 
         let opts = ctx.get_options(&OptionsType::Server);
         this.base_url = opts.require_or("api_url", "https://api.linear.app/graphql");
-        this.api_key = match opts.get("api_key") {
+
+        // `token_type 'oauth'` authenticates with a bearer access token issued to an
+        // installed Linear OAuth application instead of a personal API key, so mutations
+        // can be attributed to the app rather than an individual user.
+        let token_type = opts.require_or("token_type", "api_key");
+        let credential = match opts.get("api_key") {
             Some(key) => key,
             None => {
                 let key_id = opts.require("api_key_id")?;
                 utils::get_vault_secret(&key_id).unwrap_or_default()
             }
         };
+        this.auth_header = match token_type.as_str() {
+            "oauth" => {
+                if credential.is_empty() {
+                    return Err("token_type 'oauth' requires an 'api_key' or 'api_key_id' option carrying the OAuth2 access token".to_owned());
+                }
+                validate_oauth_credential(&this.base_url, &credential)?;
+                format!("Bearer {}", credential)
+            }
+            "api_key" => credential,
+            other => return Err(format!("Unknown token_type '{}': expected 'api_key' or 'oauth'", other)),
+        };
+        // `actor 'app'` attributes mutations made under an OAuth app token to the app
+        // itself rather than to the authorizing user; Linear only understands this header
+        // for app-authorized OAuth tokens, so it's dropped under a personal `api_key`.
+        this.actor_header = if token_type == "oauth" { opts.get("actor") } else { None };
+        this.max_retries = opts.get("max_retries").and_then(|v| v.parse().ok()).unwrap_or(3);
 
         Ok(())
     }
 
     fn begin_scan(ctx: &Context) -> FdwResult {
         let this = Self::this_mut();
-    
+
         let opts = ctx.get_options(&OptionsType::Table);
         let object = opts.require("object")?;
         let url = this.base_url.clone();
-    
+
+        // Page size for paginated (`nodes`) connections, capped at Linear's own limit
+        let page_size: u32 = opts
+            .get("page_size")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(50)
+            .min(250);
+
         // Get the list of columns requested by the user
         let columns: Vec<String> = ctx.get_columns()
             .iter()
@@ -90,6 +265,11 @@ impl Guest for LinearFdw {
             result
         }
 
+        // `sync_mode 'incremental'` needs `id`/`updatedAt` on every node to maintain the
+        // watermark and dedup against it, regardless of which columns the query actually
+        // selected, so this has to be known before the field list below is assembled.
+        let incremental = opts.get("sync_mode").as_deref() == Some("incremental");
+
         // Convert all requested fields to camelCase for GraphQL and handle object fields
         let mut graphql_fields = Vec::new();
         for col in ctx.get_columns() {
@@ -108,20 +288,78 @@ impl Guest for LinearFdw {
                 _ => graphql_fields.push(snake_to_camel(&col_name)),
             }
         }
+        if incremental {
+            if !graphql_fields.iter().any(|f| f == "id") {
+                graphql_fields.push("id".to_string());
+            }
+            if !graphql_fields.iter().any(|f| f == "updatedAt") {
+                graphql_fields.push("updatedAt".to_string());
+            }
+        }
         let fields = graphql_fields.join("\n          ");
         let mut query = String::new();
         let mut resp_pointer = String::new();
         
-        // Process any WHERE clause conditions from quals
+        // Process any WHERE clause conditions from quals into Linear's `filter` input.
+        //
+        // A dotted field name such as `team.key` or `assignee.email` walks down into a
+        // nested filter object (`team: { key: { eq: "ENG" } }`) rather than a single flat
+        // field, mirroring how Linear's own filter inputs nest relation filters. Every
+        // qual that translates is grouped under a top-level `and: [ ... ]` array so that
+        // multiple conditions on the same field compose instead of clobbering each other;
+        // quals that can't be translated are simply left out so Postgres re-checks them.
+        fn cell_to_graphql_value(cell: &Cell) -> Option<String> {
+            match cell {
+                Cell::String(s) => Some(format!("\"{}\"", escape_graphql_string(s))),
+                Cell::Bool(b) => Some(b.to_string()),
+                Cell::I32(i) => Some(i.to_string()),
+                Cell::I64(i) => Some(i.to_string()),
+                Cell::Timestamp(ts) => Some(format!("\"{}\"", escape_graphql_string(&ts.to_string()))),
+                _ => None,
+            }
+        }
+
+        // Nests `{ eq: "ENG" }` one level per dotted path segment: `team.key` -> `team: { key: { eq: "ENG" } }`.
+        fn nest_filter_path(path: &[String], leaf: &str) -> String {
+            match path.split_first() {
+                Some((head, rest)) if !rest.is_empty() => {
+                    format!("{}: {{ {} }}", head, nest_filter_path(rest, leaf))
+                }
+                Some((head, _)) => format!("{}: {{ {} }}", head, leaf),
+                None => leaf.to_string(),
+            }
+        }
+
+        // Maps a foreign table column name back to the GraphQL filter path it sits behind,
+        // mirroring the `*_id`/`state` nested-object convention already used when selecting
+        // fields (e.g. `assignee_id` -> `assignee.id`, `state` -> `state.name`).
+        fn column_to_path(col: &str) -> Vec<String> {
+            match col {
+                "state" => vec!["state".to_string(), "name".to_string()],
+                "state_id" => vec!["state".to_string(), "id".to_string()],
+                "team_id" => vec!["team".to_string(), "id".to_string()],
+                "assignee_id" => vec!["assignee".to_string(), "id".to_string()],
+                "creator_id" => vec!["creator".to_string(), "id".to_string()],
+                "parent_id" => vec!["parent".to_string(), "id".to_string()],
+                "project_id" => vec!["project".to_string(), "id".to_string()],
+                "cycle_id" => vec!["cycle".to_string(), "id".to_string()],
+                _ => vec![snake_to_camel(col)],
+            }
+        }
+
         let mut filter_conditions = String::new();
         let quals = ctx.get_quals();
+        let mut filters = Vec::new();
         if !quals.is_empty() {
-            let mut filters = Vec::new();
             for qual in quals {
-                let field = snake_to_camel(&qual.field());
+                let path: Vec<String> = if qual.field().contains('.') {
+                    qual.field().split('.').map(snake_to_camel).collect()
+                } else {
+                    column_to_path(&qual.field())
+                };
                 let operator = qual.operator();
                 let value = qual.value();
-                
+
                 // Map SQL operators to GraphQL filter operators based on Linear's schema
                 // Linear uses different filter operators than standard GraphQL
                 let filter_op = match operator.as_str() {
@@ -135,206 +373,291 @@ impl Guest for LinearFdw {
                     "!~~" => "notContains", // NOT LIKE in PostgreSQL
                     "~~*" => "containsIgnoreCase", // ILIKE in PostgreSQL
                     "!~~*" => "notContainsIgnoreCase", // NOT ILIKE in PostgreSQL
-                    "IS NULL" => "null", 
+                    "IS NULL" => "null",
                     "IS NOT NULL" => "notNull",
+                    "IN" => "in",
+                    "NOT IN" => "nin",
                     _ => continue, // Skip unsupported operators
                 };
-                
-                // Format the value based on its type
-                let formatted_value = match value {
+
+                let leaf = match value {
                     bindings::supabase::wrappers::types::Value::Cell(cell) => {
-                        match cell {
-                            Cell::String(s) => format!("\"{}\"", s),
-                            Cell::Bool(b) => b.to_string(),
-                            Cell::I32(i) => i.to_string(),
-                            Cell::I64(i) => i.to_string(),
-                            Cell::Timestamp(ts) => format!("\"{}\"", ts),
-                            _ => continue, // Skip unsupported types
+                        match cell_to_graphql_value(&cell) {
+                            Some(v) => format!("{}: {}", filter_op, v),
+                            None => continue, // Skip unsupported types
                         }
-                    },
-                    _ => continue, // Skip if no value
+                    }
+                    bindings::supabase::wrappers::types::Value::Array(cells) => {
+                        // `IN (...)` / `NOT IN (...)` map to Linear's `in`/`nin` array operators
+                        let items: Option<Vec<String>> = cells.iter().map(cell_to_graphql_value).collect();
+                        match items {
+                            Some(items) => format!("{}: [{}]", filter_op, items.join(", ")),
+                            None => continue,
+                        }
+                    }
                 };
-                
-                filters.push(format!("{}: {{ {}: {} }}", field, filter_op, formatted_value));
-            }
-            
-            if !filters.is_empty() {
-                filter_conditions = format!("filter: {{ {} }}", filters.join(", "));
+
+                filters.push(format!("{{ {} }}", nest_filter_path(&path, &leaf)));
             }
         }
 
-        // Build query and response pointer based on object/options
-        match object.as_str() {
-            "issues" => {
-                // All issues with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ issues {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ issues({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/issues/nodes".to_string();
-            },
-            "issue" => {
-                // Specific issue by id
-                let id = opts.get("id").ok_or("Missing required option 'id' for object 'issue'")?;
-                query = format!(r#"{{ issue(id: \"{}\") {{ {} }} }}"#, id, fields);
-                resp_pointer = "/data/issue".to_string();
-            },
-            "teams" => {
-                // All teams with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ teams {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ teams({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/teams/nodes".to_string();
-            },
-            "team" => {
-                // Specific team by id
-                let id = opts.get("id").ok_or("Missing required option 'id' for object 'team'")?;
-                query = format!(r#"{{ team(id: \"{}\") {{ {} }} }}"#, id, fields);
-                resp_pointer = "/data/team".to_string();
-            },
-            "projects" => {
-                // All projects with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ projects {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ projects({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/projects/nodes".to_string();
-            },
-            "project" => {
-                // Specific project by id
-                let id = opts.get("id").ok_or("Missing required option 'id' for object 'project'")?;
-                query = format!(r#"{{ project(id: \"{}\") {{ {} }} }}"#, id, fields);
-                resp_pointer = "/data/project".to_string();
-            },
+        // `sync_mode 'incremental'` resumes from the watermark committed by the previous
+        // scan instead of re-downloading the whole object set. The filter uses `gte`, not
+        // `gt`, because rows sharing the watermark's exact `updatedAt` must be re-fetched;
+        // `sync_seen_at_watermark` is what lets the loop below dedup them against what was
+        // already delivered rather than returning them twice.
+        let sync_watermark = if incremental {
+            this.sync_watermarks.get(&object).cloned()
+        } else {
+            None
+        };
+        let sync_seen_at_watermark: Vec<String> = if incremental {
+            this.sync_seen_ids.get(&object).cloned().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        if let Some(watermark) = &sync_watermark {
+            filters.push(format!("{{ updatedAt: {{ gte: \"{}\" }} }}", watermark));
+        }
+
+        if !filters.is_empty() {
+            filter_conditions = format!("filter: {{ and: [{}] }}", filters.join(", "));
+        }
+
+        // A connection is either a flat top-level `nodes` list, or one nested
+        // a level down behind a single-object lookup (e.g. `project(id) { issues { nodes } } }`).
+        // Single-object queries (`issue`, `team`, ...) have no connection at all and are
+        // fetched in one shot below.
+        enum ConnShape {
+            Flat { name: &'static str },
+            Nested { outer: &'static str, outer_id: String, inner: &'static str },
+        }
+
+        let conn_shape = match object.as_str() {
+            "issues" => Some(ConnShape::Flat { name: "issues" }),
+            "teams" => Some(ConnShape::Flat { name: "teams" }),
+            "projects" => Some(ConnShape::Flat { name: "projects" }),
+            "users" => Some(ConnShape::Flat { name: "users" }),
+            "cycles" => Some(ConnShape::Flat { name: "cycles" }),
+            "workflow_states" => Some(ConnShape::Flat { name: "workflowStates" }),
+            "issue_labels" => Some(ConnShape::Flat { name: "issueLabels" }),
             "project_issues" => {
-                // Issues within a project
                 let project_id = opts.get("project_id").ok_or("Missing required option 'project_id' for object 'project_issues'")?;
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ project(id: \"{}\") {{ issues {{ nodes {{ {} }} }} }} }}"#, project_id, fields);
-                } else {
-                    query = format!(r#"{{ project(id: \"{}\") {{ issues({}) {{ nodes {{ {} }} }} }} }}"#, project_id, filter_conditions, fields);
-                }
-                resp_pointer = "/data/project/issues/nodes".to_string();
-            },
-            "users" => {
-                // All users with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ users {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ users({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/users/nodes".to_string();
-            },
-            "user" => {
-                // Specific user by id
-                let id = opts.get("id").ok_or("Missing required option 'id' for object 'user'")?;
-                query = format!(r#"{{ user(id: \"{}\") {{ {} }} }}"#, id, fields);
-                resp_pointer = "/data/user".to_string();
+                Some(ConnShape::Nested { outer: "project", outer_id: project_id, inner: "issues" })
             },
             "user_assigned_issues" => {
-                // Issues assigned to a user
                 let user_id = opts.get("user_id").ok_or("Missing required option 'user_id' for object 'user_assigned_issues'")?;
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ user(id: \"{}\") {{ assignedIssues {{ nodes {{ {} }} }} }} }}"#, user_id, fields);
-                } else {
-                    query = format!(r#"{{ user(id: \"{}\") {{ assignedIssues({}) {{ nodes {{ {} }} }} }} }}"#, user_id, filter_conditions, fields);
-                }
-                resp_pointer = "/data/user/assignedIssues/nodes".to_string();
-            },
-            "cycles" => {
-                // All cycles with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ cycles {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ cycles({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/cycles/nodes".to_string();
+                Some(ConnShape::Nested { outer: "user", outer_id: user_id, inner: "assignedIssues" })
             },
             "cycle_issues" => {
-                // Issues in a cycle
                 let cycle_id = opts.get("cycle_id").ok_or("Missing required option 'cycle_id' for object 'cycle_issues'")?;
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ cycle(id: \"{}\") {{ issues {{ nodes {{ {} }} }} }} }}"#, cycle_id, fields);
-                } else {
-                    query = format!(r#"{{ cycle(id: \"{}\") {{ issues({}) {{ nodes {{ {} }} }} }} }}"#, cycle_id, filter_conditions, fields);
-                }
-                resp_pointer = "/data/cycle/issues/nodes".to_string();
-            },
-            "workflow_states" => {
-                // All workflow states with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ workflowStates {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ workflowStates({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/workflowStates/nodes".to_string();
-            },
-            "issue_labels" => {
-                // All issue labels with optional filter
-                if filter_conditions.is_empty() {
-                    query = format!(r#"{{ issueLabels {{ nodes {{ {} }} }} }}"#, fields);
-                } else {
-                    query = format!(r#"{{ issueLabels({}) {{ nodes {{ {} }} }} }}"#, filter_conditions, fields);
-                }
-                resp_pointer = "/data/issueLabels/nodes".to_string();
+                Some(ConnShape::Nested { outer: "cycle", outer_id: cycle_id, inner: "issues" })
             },
+            "issue" | "team" | "project" | "user" => None,
             _ => {
                 return Err(format!("Unknown object type: {}", object));
             }
+        };
+
+        // Builds the `(filter: {...}, first: N, after: "cursor", orderBy: ...)` argument
+        // list for a page. `order_by` is only set for incremental scans, which rely on
+        // ascending `updatedAt` order to advance the watermark monotonically.
+        fn page_args(filter_conditions: &str, page_size: u32, cursor: &Option<String>, order_by: Option<&str>) -> String {
+            let mut parts = Vec::new();
+            if !filter_conditions.is_empty() {
+                parts.push(filter_conditions.to_string());
+            }
+            parts.push(format!("first: {}", page_size));
+            if let Some(cursor) = cursor {
+                parts.push(format!("after: \"{}\"", cursor));
+            }
+            if let Some(order_by) = order_by {
+                parts.push(format!("orderBy: {}", order_by));
+            }
+            parts.join(", ")
         }
 
-        utils::report_info(&format!("GraphQL Query: {}", query));
+        this.src_rows = Vec::new();
+        this.src_idx = 0;
+        // Running high-water mark for this scan, seeded from the committed watermark so a
+        // scan that finds no new rows leaves the persisted state unchanged.
+        let mut sync_running_max = sync_watermark.clone();
+        let mut sync_running_ids_at_max = sync_seen_at_watermark.clone();
 
-        let body = serde_json::json!({
-            "query": query
-        }).to_string();
+        if let Some(shape) = conn_shape {
+            // Paginate: loop issuing follow-up POSTs until `hasNextPage` is false,
+            // appending each page's nodes into `src_rows`.
+            let conn_pointer = match &shape {
+                ConnShape::Flat { name } => format!("/data/{}", name),
+                ConnShape::Nested { outer, inner, .. } => format!("/data/{}/{}", outer, inner),
+            };
+            let nodes_pointer = format!("{}/nodes", conn_pointer);
+            let page_info_pointer = format!("{}/pageInfo", conn_pointer);
 
-        let headers = vec![
-            ("content-type".to_owned(), "application/json".to_owned()),
-            ("authorization".to_owned(), this.api_key.to_owned()),
-        ];
+            let mut cursor: Option<String> = None;
+            loop {
+                let args = page_args(&filter_conditions, page_size, &cursor, if incremental { Some("updatedAt") } else { None });
+                query = match &shape {
+                    ConnShape::Flat { name } => format!(
+                        r#"{{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}"#,
+                        name, args, fields
+                    ),
+                    ConnShape::Nested { outer, outer_id, inner } => format!(
+                        r#"{{ {}(id: "{}") {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }} }}"#,
+                        outer, escape_graphql_string(outer_id), inner, args, fields
+                    ),
+                };
 
-        let req = http::Request {
-            method: http::Method::Post,
-            url,
-            headers,
-            body,
-        };
+                utils::report_info(&format!("GraphQL Query: {}", query));
 
-        let resp = http::post(&req)?;
+                let resp = post_with_retry(
+                    || http::Request {
+                        method: http::Method::Post,
+                        url: url.clone(),
+                        headers: vec![
+                            ("content-type".to_owned(), "application/json".to_owned()),
+                            ("authorization".to_owned(), this.auth_header.to_owned()),
+                        ],
+                        body: serde_json::json!({ "query": query }).to_string(),
+                    },
+                    this.max_retries,
+                )?;
+                if resp.status_code != 200 {
+                    return Err(format!("Failed to get data: {}", resp.body));
+                }
 
-        if resp.status_code != 200 {
-            return Err(format!("Failed to get data: {}", resp.body));
-        }
-        
-        // Check for GraphQL errors in the response
-        let resp_json: JsonValue = serde_json::from_str(&resp.body)
-            .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
-            
-        if let Some(errors) = resp_json.get("errors") {
-            if let Some(errors_array) = errors.as_array() {
-                if !errors_array.is_empty() {
-                    return Err(format!("GraphQL errors: {}", serde_json::to_string(errors).unwrap_or_default()));
+                let resp_json: JsonValue = serde_json::from_str(&resp.body)
+                    .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+
+                if let Some(errors) = resp_json.get("errors") {
+                    if let Some(errors_array) = errors.as_array() {
+                        if !errors_array.is_empty() {
+                            return Err(format!("GraphQL errors: {}", serde_json::to_string(errors).unwrap_or_default()));
+                        }
+                    }
+                }
+
+                if let Some(items) = resp_json.pointer(&nodes_pointer).and_then(|v| v.as_array()) {
+                    if incremental {
+                        for item in items {
+                            let item_id = item.get("id").and_then(|v| v.as_str()).unwrap_or_default();
+                            let item_updated_at = item.get("updatedAt").and_then(|v| v.as_str()).unwrap_or_default();
+
+                            // Already delivered in a prior scan at this exact boundary timestamp.
+                            if sync_watermark.as_deref() == Some(item_updated_at)
+                                && sync_seen_at_watermark.iter().any(|id| id == item_id)
+                            {
+                                continue;
+                            }
+
+                            match sync_running_max.as_deref() {
+                                Some(max) if item_updated_at < max => {}
+                                Some(max) if item_updated_at == max => {
+                                    sync_running_ids_at_max.push(item_id.to_owned());
+                                }
+                                _ => {
+                                    sync_running_max = Some(item_updated_at.to_owned());
+                                    sync_running_ids_at_max = vec![item_id.to_owned()];
+                                }
+                            }
+
+                            this.src_rows.push(item.to_owned());
+                        }
+                    } else {
+                        this.src_rows.extend(items.to_owned());
+                    }
+                }
+
+                let has_next_page = resp_json
+                    .pointer(&page_info_pointer)
+                    .and_then(|v| v.get("hasNextPage"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+
+                if !has_next_page {
+                    break;
+                }
+
+                cursor = resp_json
+                    .pointer(&page_info_pointer)
+                    .and_then(|v| v.get("endCursor"))
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_owned());
+
+                if cursor.is_none() {
+                    break;
                 }
             }
-        }
 
-        // Always flatten to an array for iter_scan
-        if let Some(arr) = resp_json.pointer(&resp_pointer) {
-            if let Some(items) = arr.as_array() {
-                this.src_rows = items.to_owned();
-            } else if arr.is_object() {
-                // For single object queries
-                this.src_rows = vec![arr.to_owned()];
-            } else {
-                this.src_rows = vec![];
+            // Every page has now been consumed, so it's safe to stage the new watermark.
+            // `end_scan` is what actually commits it, so a `begin_scan` that errors out
+            // partway through the loop above never reaches here and leaves the previously
+            // committed watermark untouched.
+            if incremental {
+                if let Some(max) = sync_running_max {
+                    this.sync_pending = Some((object.clone(), max, sync_running_ids_at_max));
+                }
             }
         } else {
-            this.src_rows = vec![];
+            // Single-object lookup by id, no pagination.
+            resp_pointer = match object.as_str() {
+                "issue" => {
+                    let id = opts.get("id").ok_or("Missing required option 'id' for object 'issue'")?;
+                    query = format!(r#"{{ issue(id: "{}") {{ {} }} }}"#, escape_graphql_string(&id), fields);
+                    "/data/issue".to_string()
+                },
+                "team" => {
+                    let id = opts.get("id").ok_or("Missing required option 'id' for object 'team'")?;
+                    query = format!(r#"{{ team(id: "{}") {{ {} }} }}"#, escape_graphql_string(&id), fields);
+                    "/data/team".to_string()
+                },
+                "project" => {
+                    let id = opts.get("id").ok_or("Missing required option 'id' for object 'project'")?;
+                    query = format!(r#"{{ project(id: "{}") {{ {} }} }}"#, escape_graphql_string(&id), fields);
+                    "/data/project".to_string()
+                },
+                "user" => {
+                    let id = opts.get("id").ok_or("Missing required option 'id' for object 'user'")?;
+                    query = format!(r#"{{ user(id: "{}") {{ {} }} }}"#, escape_graphql_string(&id), fields);
+                    "/data/user".to_string()
+                },
+                _ => unreachable!(),
+            };
+
+            utils::report_info(&format!("GraphQL Query: {}", query));
+
+            let resp = post_with_retry(
+                || http::Request {
+                    method: http::Method::Post,
+                    url: url.clone(),
+                    headers: vec![
+                        ("content-type".to_owned(), "application/json".to_owned()),
+                        ("authorization".to_owned(), this.auth_header.to_owned()),
+                    ],
+                    body: serde_json::json!({ "query": query }).to_string(),
+                },
+                this.max_retries,
+            )?;
+            if resp.status_code != 200 {
+                return Err(format!("Failed to get data: {}", resp.body));
+            }
+
+            let resp_json: JsonValue = serde_json::from_str(&resp.body)
+                .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+
+            if let Some(errors) = resp_json.get("errors") {
+                if let Some(errors_array) = errors.as_array() {
+                    if !errors_array.is_empty() {
+                        return Err(format!("GraphQL errors: {}", serde_json::to_string(errors).unwrap_or_default()));
+                    }
+                }
+            }
+
+            if let Some(obj) = resp_json.pointer(&resp_pointer) {
+                if obj.is_object() {
+                    this.src_rows = vec![obj.to_owned()];
+                }
+            }
         }
 
         utils::report_info(&format!("Got {} rows", this.src_rows.len()));
@@ -516,323 +839,606 @@ impl Guest for LinearFdw {
     }
 
     fn import_foreign_schema(
-    _ctx: &Context,
-    stmt: ImportForeignSchemaStmt,
-) -> Result<Vec<String>, FdwError> {
-    let ret = vec![
-        // All issues with extended fields
-        format!(
-            r#"-- GraphQL: {{ issues {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists issues (
-id text,
-title text,
-description text,
-number float,
-priority float,
-estimate float,
-sub_issue_sort_order float,
-priority_sort_order float,
-state text,
-state_id text,
-team_id text,
-assignee_id text,
-creator_id text,
-parent_id text,
-project_id text,
-cycle_id text,
-created_at timestamptz,
-updated_at timestamptz,
-started_at timestamptz,
-completed_at timestamptz,
-archived_at timestamptz,
-sort_order float,
-due_date timestamptz,
-url text
-) server {} options (
-object 'issues'
-);"#,
-            stmt.server_name,
-        ),
-        // A specific issue with extended fields
-        format!(
-            r#"-- GraphQL: {{ issue(id: $id) {{ ...fields }} }}
-create foreign table if not exists issue (
-id text,
-title text,
-description text,
-number float,
-priority float,
-estimate float,
-sub_issue_sort_order float,
-priority_sort_order float,
-state text,
-state_id text,
-team_id text,
-assignee_id text,
-creator_id text,
-parent_id text,
-project_id text,
-cycle_id text,
-created_at timestamptz,
-updated_at timestamptz,
-started_at timestamptz,
-completed_at timestamptz,
-archived_at timestamptz,
-sort_order float,
-due_date timestamptz,
-url text
-) server {} options (
-object 'issue',
-id 'YOUR_ISSUE_ID'
-);"#,
-            stmt.server_name,
-        ),
-        // All teams
-        format!(
-            r#"-- GraphQL: {{ teams {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists teams (
-id text,
-name text,
-key text,
-description text,
-icon text,
-color text,
-cycles_enabled boolean,
-cycle_start_day float,
-cycle_duration float,
-timezone text,
-triage_enabled boolean,
-private boolean,
-created_at timestamptz,
-updated_at timestamptz,
-archived_at timestamptz
-) server {} options (
-object 'teams'
-);"#,
-            stmt.server_name,
-        ),
-        // All projects with extended fields
-        format!(
-            r#"-- GraphQL: {{ projects {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists projects (
-id text,
-name text,
-description text,
-icon text,
-color text,
-state text,
-slug text,
-team_id text,
-creator_id text,
-lead_id text,
-sort_order float,
-start_date timestamptz,
-target_date timestamptz,
-completed_at timestamptz,
-created_at timestamptz,
-updated_at timestamptz,
-archived_at timestamptz,
-url text
-) server {} options (
-object 'projects'
-);"#,
-            stmt.server_name,
-        ),
-        // Issues within a project
-        format!(
-            r#"-- GraphQL: {{ project(id: $project_id) {{ issues {{ nodes {{ ...fields }} }} }} }}
-create foreign table if not exists project_issues (
-id text,
-title text,
-description text,
-number float,
-priority float,
-estimate float,
-state text,
-state_id text,
-team_id text,
-assignee_id text,
-creator_id text,
-project_id text,
-created_at timestamptz,
-updated_at timestamptz,
-started_at timestamptz,
-completed_at timestamptz,
-archived_at timestamptz,
-url text
-) server {} options (
-object 'project_issues',
-project_id 'YOUR_PROJECT_ID'
-);"#,
-            stmt.server_name,
-        ),
-        // All users
-        format!(
-            r#"-- GraphQL: {{ users {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists users (
-id text,
-name text,
-display_name text,
-email text,
-avatar_url text,
-description text,
-timezone text,
-last_seen timestamptz,
-active boolean,
-url text,
-created_at timestamptz,
-updated_at timestamptz,
-archived_at timestamptz
-) server {} options (
-object 'users'
-);"#,
-            stmt.server_name,
-        ),
-        // Issues assigned to a user
-        format!(
-            r#"-- GraphQL: {{ user(id: $user_id) {{ assignedIssues {{ nodes {{ ...fields }} }} }} }}
-create foreign table if not exists user_assigned_issues (
-id text,
-title text,
-description text,
-number float,
-priority float,
-estimate float,
-state text,
-team_id text,
-assignee_id text,
-creator_id text,
-project_id text,
-created_at timestamptz,
-updated_at timestamptz,
-started_at timestamptz,
-completed_at timestamptz,
-archived_at timestamptz,
-url text
-) server {} options (
-object 'user_assigned_issues',
-user_id 'YOUR_USER_ID'
-);"#,
-            stmt.server_name,
-        ),
-        // All cycles
-        format!(
-            r#"-- GraphQL: {{ cycles {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists cycles (
-id text,
-number float,
-name text,
-description text,
-start_date timestamptz,
-end_date timestamptz,
-completed_at timestamptz,
-team_id text,
-created_at timestamptz,
-updated_at timestamptz,
-archived_at timestamptz
-) server {} options (
-object 'cycles'
-);"#,
-            stmt.server_name,
-        ),
-        // Issues in a cycle
-        format!(
-            r#"-- GraphQL: {{ cycle(id: $cycle_id) {{ issues {{ nodes {{ ...fields }} }} }} }}
-create foreign table if not exists cycle_issues (
-id text,
-title text,
-description text,
-number float,
-priority float,
-estimate float,
-state text,
-team_id text,
-assignee_id text,
-creator_id text,
-project_id text,
-cycle_id text,
-created_at timestamptz,
-updated_at timestamptz,
-started_at timestamptz,
-completed_at timestamptz,
-archived_at timestamptz,
-url text
-) server {} options (
-object 'cycle_issues',
-cycle_id 'YOUR_CYCLE_ID'
-);"#,
-            stmt.server_name,
-        ),
-        // All workflow states
-        format!(
-            r#"-- GraphQL: {{ workflowStates {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists workflow_states (
-id text,
-name text,
-description text,
-color text,
-type text,
-position float,
-team_id text,
-created_at timestamptz,
-updated_at timestamptz,
-archived_at timestamptz
-) server {} options (
-object 'workflow_states'
-);"#,
-            stmt.server_name,
-        ),
-        // All issue labels
-        format!(
-            r#"-- GraphQL: {{ issueLabels {{ nodes {{ ...fields }} }} }}
-create foreign table if not exists issue_labels (
-id text,
-name text,
-description text,
-color text,
-team_id text,
-created_at timestamptz,
-updated_at timestamptz,
-archived_at timestamptz
-) server {} options (
-object 'issue_labels'
-);"#,
-            stmt.server_name,
-        ),
-    ];
-
-    Ok(ret)
-}
+        ctx: &Context,
+        stmt: ImportForeignSchemaStmt,
+    ) -> Result<Vec<String>, FdwError> {
+        // Foreign tables this wrapper can back, keyed by the `object` option they use and
+        // the GraphQL object type that provides their fields.
+        struct TableSpec {
+            table_name: &'static str,
+            object: &'static str,
+            // The field this table's rows come from on the root `Query` type, used to
+            // confirm the table is actually reachable in the live schema.
+            root_field: &'static str,
+            graphql_type: &'static str,
+            extra_options: &'static [(&'static str, &'static str)],
+            graphql_comment: &'static str,
+        }
 
-fn re_scan(_ctx: &Context) -> FdwResult {
-    Err("re_scan on foreign table is not supported".to_owned())
-}
+        const TABLE_SPECS: &[TableSpec] = &[
+            TableSpec { table_name: "issues", object: "issues", root_field: "issues", graphql_type: "Issue", extra_options: &[], graphql_comment: "{ issues { nodes { ...fields } } }" },
+            TableSpec { table_name: "issue", object: "issue", root_field: "issue", graphql_type: "Issue", extra_options: &[("id", "YOUR_ISSUE_ID")], graphql_comment: "{ issue(id: $id) { ...fields } }" },
+            TableSpec { table_name: "teams", object: "teams", root_field: "teams", graphql_type: "Team", extra_options: &[], graphql_comment: "{ teams { nodes { ...fields } } }" },
+            TableSpec { table_name: "projects", object: "projects", root_field: "projects", graphql_type: "Project", extra_options: &[], graphql_comment: "{ projects { nodes { ...fields } } }" },
+            TableSpec { table_name: "project_issues", object: "project_issues", root_field: "project", graphql_type: "Issue", extra_options: &[("project_id", "YOUR_PROJECT_ID")], graphql_comment: "{ project(id: $project_id) { issues { nodes { ...fields } } } }" },
+            TableSpec { table_name: "users", object: "users", root_field: "users", graphql_type: "User", extra_options: &[], graphql_comment: "{ users { nodes { ...fields } } }" },
+            TableSpec { table_name: "user_assigned_issues", object: "user_assigned_issues", root_field: "user", graphql_type: "Issue", extra_options: &[("user_id", "YOUR_USER_ID")], graphql_comment: "{ user(id: $user_id) { assignedIssues { nodes { ...fields } } } }" },
+            TableSpec { table_name: "cycles", object: "cycles", root_field: "cycles", graphql_type: "Cycle", extra_options: &[], graphql_comment: "{ cycles { nodes { ...fields } } }" },
+            TableSpec { table_name: "cycle_issues", object: "cycle_issues", root_field: "cycle", graphql_type: "Issue", extra_options: &[("cycle_id", "YOUR_CYCLE_ID")], graphql_comment: "{ cycle(id: $cycle_id) { issues { nodes { ...fields } } } }" },
+            TableSpec { table_name: "workflow_states", object: "workflow_states", root_field: "workflowStates", graphql_type: "WorkflowState", extra_options: &[], graphql_comment: "{ workflowStates { nodes { ...fields } } }" },
+            TableSpec { table_name: "issue_labels", object: "issue_labels", root_field: "issueLabels", graphql_type: "IssueLabel", extra_options: &[], graphql_comment: "{ issueLabels { nodes { ...fields } } }" },
+        ];
 
-fn end_scan(_ctx: &Context) -> FdwResult {
-    let this = Self::this_mut();
-    this.src_rows.clear();
-    Ok(())
-}
+        // An `import '<comma-separated table names>'` option on the IMPORT FOREIGN SCHEMA
+        // statement is an alternative,
+        // finer-grained way to select objects alongside `limit to (...)` / `except (...)`.
+        let import_option: Option<Vec<String>> = stmt
+            .options
+            .iter()
+            .find(|(k, _)| k == "import")
+            .map(|(_, v)| v.split(',').map(|s| s.trim().to_string()).collect());
 
-fn begin_modify(_ctx: &Context) -> FdwResult {
-    Err("modify on foreign table is not supported".to_owned())
-}
+        // Honor `limit to (...)` / `except (...)` from the IMPORT FOREIGN SCHEMA statement.
+        let specs: Vec<&TableSpec> = TABLE_SPECS
+            .iter()
+            .filter(|s| match stmt.list_type {
+                ImportForeignSchemaType::FdwImportSchemaLimitTo => {
+                    stmt.table_list.iter().any(|n| n == s.table_name)
+                }
+                ImportForeignSchemaType::FdwImportSchemaExcept => {
+                    !stmt.table_list.iter().any(|n| n == s.table_name)
+                }
+                ImportForeignSchemaType::FdwImportSchemaAll => true,
+            })
+            .filter(|s| match &import_option {
+                Some(names) => names.iter().any(|n| n == s.table_name),
+                None => true,
+            })
+            .collect();
+        if specs.is_empty() {
+            return Ok(vec![]);
+        }
 
-fn insert(_ctx: &Context, _row: &Row) -> FdwResult {
-    Ok(())
-}
+        // Run the standard GraphQL introspection query to discover each type's live fields,
+        // so new Linear fields show up here without editing Rust.
+        let opts = ctx.get_options(&OptionsType::Server);
+        let base_url = opts.require_or("api_url", "https://api.linear.app/graphql");
+        let token_type = opts.require_or("token_type", "api_key");
+        let credential = match opts.get("api_key") {
+            Some(key) => key,
+            None => {
+                let key_id = opts.require("api_key_id")?;
+                utils::get_vault_secret(&key_id).unwrap_or_default()
+            }
+        };
+        let auth_header = match token_type.as_str() {
+            "oauth" => format!("Bearer {}", credential),
+            "api_key" => credential,
+            other => return Err(format!("Unknown token_type '{}': expected 'api_key' or 'oauth'", other)),
+        };
+
+        let introspection_query = r#"{ __schema { queryType { name } types { name fields { name type { kind name ofType { kind name } } } } } }"#;
+        let max_retries = opts.get("max_retries").and_then(|v| v.parse().ok()).unwrap_or(3);
+        let resp = post_with_retry(
+            || http::Request {
+                method: http::Method::Post,
+                url: base_url.clone(),
+                headers: vec![
+                    ("content-type".to_owned(), "application/json".to_owned()),
+                    ("authorization".to_owned(), auth_header.clone()),
+                ],
+                body: serde_json::json!({ "query": introspection_query }).to_string(),
+            },
+            max_retries,
+        )?;
+        if resp.status_code != 200 {
+            return Err(format!("Schema introspection failed: {}", resp.body));
+        }
+        let resp_json: JsonValue = serde_json::from_str(&resp.body)
+            .map_err(|e| format!("Failed to parse introspection response: {}", e))?;
+        let types = resp_json
+            .pointer("/data/__schema/types")
+            .and_then(|v| v.as_array())
+            .ok_or("Malformed introspection response: missing __schema.types")?;
+
+        // Only keep specs whose root field is actually reachable from the live `Query` type.
+        let query_type_name = resp_json
+            .pointer("/data/__schema/queryType/name")
+            .and_then(|v| v.as_str());
+        let query_fields: Vec<&str> = query_type_name
+            .and_then(|name| types.iter().find(|t| t.get("name").and_then(|n| n.as_str()) == Some(name)))
+            .and_then(|t| t.get("fields"))
+            .and_then(|f| f.as_array())
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(|f| f.get("name").and_then(|n| n.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let specs: Vec<&TableSpec> = specs
+            .into_iter()
+            .filter(|s| {
+                if query_fields.is_empty() || query_fields.contains(&s.root_field) {
+                    true
+                } else {
+                    utils::report_info(&format!(
+                        "Linear schema has no root field '{}', skipping table '{}'",
+                        s.root_field, s.table_name
+                    ));
+                    false
+                }
+            })
+            .collect();
+
+        // camelCase -> snake_case, the inverse of the mapping used when building queries.
+        fn camel_to_snake(s: &str) -> String {
+            let mut out = String::new();
+            for c in s.chars() {
+                if c.is_ascii_uppercase() {
+                    out.push('_');
+                    out.push(c.to_ascii_lowercase());
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        }
+
+        // GraphQL `Int` is a 32-bit signed integer, so it's widened to `bigint` rather than
+        // narrowed to Postgres' own 32-bit `integer`; that's the canonical mapping this
+        // wrapper uses everywhere scalars are generated, Postgres-type-wise.
+        fn scalar_to_pg_type(name: &str) -> Option<&'static str> {
+            match name {
+                "String" | "ID" => Some("text"),
+                "Int" => Some("bigint"),
+                "Float" => Some("float"),
+                "Boolean" => Some("boolean"),
+                "DateTime" => Some("timestamptz"),
+                _ => None,
+            }
+        }
+
+        // Object fields `begin_scan`/`iter_scan` already know how to select a nested
+        // sub-object for and flatten into a `<field>_id` column (see the hardcoded
+        // `graphql_fields`/`column_to_path` match arms). Any other `OBJECT` field would
+        // need a sub-selection the scanner doesn't build, which Linear rejects outright
+        // ("must have a selection of subfields"), so those are skipped rather than
+        // generating a column nothing can ever populate.
+        const SUPPORTED_RELATION_FIELDS: &[&str] =
+            &["state", "team", "assignee", "creator", "parent", "project", "cycle"];
+        // Object fields that already get a `_id` column but should also surface a
+        // human-readable scalar, mirroring the hand-written `state`/`state_id` pair.
+        const NAME_SHADOW_FIELDS: &[&str] = &["state"];
+        // List-of-object connections that collapse into a `json` column instead of being skipped.
+        const JSON_LIST_FIELDS: &[&str] = &["labels"];
+
+        let mut ret = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let type_def = types
+                .iter()
+                .find(|t| t.get("name").and_then(|n| n.as_str()) == Some(spec.graphql_type));
+
+            let mut columns = vec!["id text".to_string()];
+
+            match type_def {
+                Some(type_def) => {
+                    if let Some(fields) = type_def.get("fields").and_then(|f| f.as_array()) {
+                        for field in fields {
+                            let field_name = match field.get("name").and_then(|n| n.as_str()) {
+                                Some(n) if n != "id" => n,
+                                _ => continue,
+                            };
+                            let field_type = match field.get("type") {
+                                Some(t) => t,
+                                None => continue,
+                            };
+
+                            // Unwrap one level of NON_NULL/LIST to get at the named type,
+                            // matching the shape of the introspection query above.
+                            let mut kind = field_type.get("kind").and_then(|k| k.as_str()).unwrap_or("");
+                            let mut name = field_type.get("name").and_then(|n| n.as_str());
+                            if name.is_none() {
+                                if let Some(of_type) = field_type.get("ofType") {
+                                    kind = of_type.get("kind").and_then(|k| k.as_str()).unwrap_or(kind);
+                                    name = of_type.get("name").and_then(|n| n.as_str());
+                                }
+                            }
+
+                            let snake_name = camel_to_snake(field_name);
+                            match kind {
+                                "SCALAR" | "ENUM" => {
+                                    let pg_type = name.and_then(scalar_to_pg_type).unwrap_or("text");
+                                    columns.push(format!("{} {}", snake_name, pg_type));
+                                }
+                                "OBJECT" if SUPPORTED_RELATION_FIELDS.contains(&field_name) => {
+                                    columns.push(format!("{}_id text", snake_name));
+                                    if NAME_SHADOW_FIELDS.contains(&field_name) {
+                                        columns.push(format!("{} text", snake_name));
+                                    }
+                                }
+                                "OBJECT" | "LIST" if JSON_LIST_FIELDS.contains(&field_name) => {
+                                    columns.push(format!("{} json", snake_name));
+                                }
+                                "OBJECT" => {
+                                    utils::report_info(&format!(
+                                        "Linear field '{}' is an object relation the scanner doesn't support yet, skipping column",
+                                        field_name
+                                    ));
+                                }
+                                _ => {} // skip list-of-object connections we don't flatten
+                            }
+                        }
+                    }
+                }
+                None => {
+                    utils::report_info(&format!(
+                        "Linear schema has no type '{}' for table '{}', importing with 'id' only",
+                        spec.graphql_type, spec.table_name
+                    ));
+                }
+            }
+
+            let options = std::iter::once(format!("object '{}'", spec.object))
+                .chain(spec.extra_options.iter().map(|(k, v)| format!("{} '{}'", k, v)))
+                .collect::<Vec<_>>()
+                .join(",\n");
+
+            ret.push(format!(
+                "-- GraphQL: {}\ncreate foreign table if not exists {} (\n{}\n) server {} options (\n{}\n);",
+                spec.graphql_comment,
+                spec.table_name,
+                columns.join(",\n"),
+                stmt.server_name,
+                options,
+            ));
+        }
+
+        Ok(ret)
+    }
+
+    fn re_scan(_ctx: &Context) -> FdwResult {
+        // `begin_scan` already resumes from the persisted watermark (or re-fetches the
+        // whole object set for non-incremental tables), so a re-scan just replays the rows
+        // already buffered in `src_rows` instead of failing outright.
+        let this = Self::this_mut();
+        this.src_idx = 0;
+        Ok(())
+    }
+
+    fn end_scan(_ctx: &Context) -> FdwResult {
+        let this = Self::this_mut();
+        // Commit the watermark staged by `begin_scan` only now that the scan has run to
+        // completion, so an interrupted scan leaves the previously committed watermark in
+        // place rather than skipping the rows it never got back to.
+        if let Some((object, watermark, seen_ids)) = this.sync_pending.take() {
+            this.sync_watermarks.insert(object.clone(), watermark);
+            this.sync_seen_ids.insert(object, seen_ids);
+        }
+        this.src_rows.clear();
+        Ok(())
+    }
+
+    fn begin_modify(ctx: &Context) -> FdwResult {
+        let this = Self::this_mut();
+        let opts = ctx.get_options(&OptionsType::Table);
+        let object = opts.require("object")?;
+        mutation_names(&object)?;
+
+        let rowid_col = ctx
+            .get_columns()
+            .iter()
+            .find(|c| c.name() == "id")
+            .map(|c| c.name())
+            .ok_or_else(|| format!("Table for object '{}' must have an 'id' column to be writable", object))?;
+
+        this.modify_object = object;
+        this.modify_rowid_col = rowid_col;
+        Ok(())
+    }
+
+    fn insert(ctx: &Context, row: &Row) -> FdwResult {
+        let this = Self::this_mut();
+        let mutations = mutation_names(&this.modify_object)?;
+        let create_mutation = mutations.create;
+
+        fn cell_to_graphql_literal(cell: &Cell) -> Option<String> {
+            match cell {
+                Cell::String(s) => Some(format!("\"{}\"", escape_graphql_string(s))),
+                Cell::Bool(b) => Some(b.to_string()),
+                Cell::I32(i) => Some(i.to_string()),
+                Cell::I64(i) => Some(i.to_string()),
+                Cell::Timestamp(ts) => Some(format!("\"{}\"", escape_graphql_string(&ts.to_string()))),
+                _ => None,
+            }
+        }
+
+        let mut input_fields = Vec::new();
+        for (col, cell) in ctx.get_columns().iter().zip(row.cells().iter()) {
+            let col_name = col.name();
+            if col_name == this.modify_rowid_col {
+                continue; // server-assigned
+            }
+            let input_name = match mutation_input_field(&col_name) {
+                Some(name) => name,
+                None => continue, // server-computed / read-only column, not a valid *CreateInput field
+            };
+            if let Some(cell) = cell {
+                if let Some(literal) = cell_to_graphql_literal(cell) {
+                    input_fields.push(format!("{}: {}", input_name, literal));
+                }
+            }
+        }
+
+        let mutation = format!(
+            r#"mutation {{ {}(input: {{ {} }}) {{ success }} }}"#,
+            create_mutation,
+            input_fields.join(", "),
+        );
+
+        let mut headers = vec![
+            ("content-type".to_owned(), "application/json".to_owned()),
+            ("authorization".to_owned(), this.auth_header.to_owned()),
+        ];
+        if let Some(actor) = &this.actor_header {
+            headers.push(("linear-actor".to_owned(), actor.to_owned()));
+        }
+
+        let resp = post_with_retry(
+            || http::Request {
+                method: http::Method::Post,
+                url: this.base_url.clone(),
+                headers: headers.clone(),
+                body: serde_json::json!({ "query": mutation }).to_string(),
+            },
+            this.max_retries,
+        )?;
+        if resp.status_code != 200 {
+            return Err(format!("Failed to create {}: {}", this.modify_object, resp.body));
+        }
+
+        let resp_json: JsonValue = serde_json::from_str(&resp.body)
+            .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+
+        if let Some(errors) = resp_json.get("errors").and_then(|e| e.as_array()) {
+            if !errors.is_empty() {
+                return Err(format!("GraphQL errors: {}", serde_json::to_string(errors).unwrap_or_default()));
+            }
+        }
+
+        let success = resp_json
+            .pointer(&format!("/data/{}/success", create_mutation))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !success {
+            return Err(format!("{} failed", create_mutation));
+        }
+
+        // `Row` only exposes `push`, not a way to overwrite an individual cell after the
+        // fact, so there's no way to hand the server-assigned id back for `RETURNING` here.
+        // The mutation only selects `success`, and an `id` supplied in the inserted row is
+        // not sent, so the new row's id is not available from this call.
+        Ok(())
+    }
+
+    fn update(ctx: &Context, rowid: Cell, row: &Row) -> FdwResult {
+        let this = Self::this_mut();
+        let mutations = mutation_names(&this.modify_object)?;
+        let update_mutation = mutations.update;
+
+        fn cell_to_graphql_literal(cell: &Cell) -> Option<String> {
+            match cell {
+                Cell::String(s) => Some(format!("\"{}\"", escape_graphql_string(s))),
+                Cell::Bool(b) => Some(b.to_string()),
+                Cell::I32(i) => Some(i.to_string()),
+                Cell::I64(i) => Some(i.to_string()),
+                Cell::Timestamp(ts) => Some(format!("\"{}\"", escape_graphql_string(&ts.to_string()))),
+                _ => None,
+            }
+        }
+
+        let id_literal = cell_to_graphql_literal(&rowid).ok_or("Unsupported rowid cell type")?;
+
+        let mut input_fields = Vec::new();
+        for (col, cell) in ctx.get_columns().iter().zip(row.cells().iter()) {
+            let col_name = col.name();
+            if col_name == this.modify_rowid_col {
+                continue;
+            }
+            let input_name = match mutation_input_field(&col_name) {
+                Some(name) => name,
+                None => continue, // server-computed / read-only column, not a valid *UpdateInput field
+            };
+            if let Some(cell) = cell {
+                if let Some(literal) = cell_to_graphql_literal(cell) {
+                    input_fields.push(format!("{}: {}", input_name, literal));
+                }
+            }
+        }
+
+        let mutation = format!(
+            r#"mutation {{ {}(id: {}, input: {{ {} }}) {{ success }} }}"#,
+            update_mutation,
+            id_literal,
+            input_fields.join(", "),
+        );
+
+        let mut headers = vec![
+            ("content-type".to_owned(), "application/json".to_owned()),
+            ("authorization".to_owned(), this.auth_header.to_owned()),
+        ];
+        if let Some(actor) = &this.actor_header {
+            headers.push(("linear-actor".to_owned(), actor.to_owned()));
+        }
+
+        let resp = post_with_retry(
+            || http::Request {
+                method: http::Method::Post,
+                url: this.base_url.clone(),
+                headers: headers.clone(),
+                body: serde_json::json!({ "query": mutation }).to_string(),
+            },
+            this.max_retries,
+        )?;
+        if resp.status_code != 200 {
+            return Err(format!("Failed to update {}: {}", this.modify_object, resp.body));
+        }
+
+        let resp_json: JsonValue = serde_json::from_str(&resp.body)
+            .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+
+        if let Some(errors) = resp_json.get("errors").and_then(|e| e.as_array()) {
+            if !errors.is_empty() {
+                return Err(format!("GraphQL errors: {}", serde_json::to_string(errors).unwrap_or_default()));
+            }
+        }
+
+        let success = resp_json
+            .pointer(&format!("/data/{}/success", update_mutation))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !success {
+            return Err(format!("{} failed", update_mutation));
+        }
 
-    fn update(_ctx: &Context, _rowid: Cell, _row: &Row) -> FdwResult {
         Ok(())
     }
 
-    fn delete(_ctx: &Context, _rowid: Cell) -> FdwResult {
+    fn delete(ctx: &Context, rowid: Cell) -> FdwResult {
+        let this = Self::this_mut();
+        let mutations = mutation_names(&this.modify_object)?;
+
+        // Issues default to the soft `archive` mutation; set `delete_mode 'hard'` on the
+        // table to call the permanent `delete` mutation instead.
+        let opts = ctx.get_options(&OptionsType::Table);
+        let hard_delete = opts.get("delete_mode").as_deref() == Some("hard");
+        let delete_mutation = if hard_delete {
+            mutations.delete
+        } else {
+            mutations.archive.unwrap_or(mutations.delete)
+        };
+
+        let id_literal = match &rowid {
+            Cell::String(s) => format!("\"{}\"", s),
+            _ => return Err("Unsupported rowid cell type".to_owned()),
+        };
+
+        let mutation = format!(r#"mutation {{ {}(id: {}) {{ success }} }}"#, delete_mutation, id_literal);
+
+        let mut headers = vec![
+            ("content-type".to_owned(), "application/json".to_owned()),
+            ("authorization".to_owned(), this.auth_header.to_owned()),
+        ];
+        if let Some(actor) = &this.actor_header {
+            headers.push(("linear-actor".to_owned(), actor.to_owned()));
+        }
+
+        let resp = post_with_retry(
+            || http::Request {
+                method: http::Method::Post,
+                url: this.base_url.clone(),
+                headers: headers.clone(),
+                body: serde_json::json!({ "query": mutation }).to_string(),
+            },
+            this.max_retries,
+        )?;
+        if resp.status_code != 200 {
+            return Err(format!("Failed to delete {}: {}", this.modify_object, resp.body));
+        }
+
+        let resp_json: JsonValue = serde_json::from_str(&resp.body)
+            .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+
+        if let Some(errors) = resp_json.get("errors").and_then(|e| e.as_array()) {
+            if !errors.is_empty() {
+                return Err(format!("GraphQL errors: {}", serde_json::to_string(errors).unwrap_or_default()));
+            }
+        }
+
+        let success = resp_json
+            .pointer(&format!("/data/{}/success", delete_mutation))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !success {
+            return Err(format!("{} failed", delete_mutation));
+        }
+
         Ok(())
     }
 
     fn end_modify(_ctx: &Context) -> FdwResult {
+        // No operations are batched, so there's nothing to flush beyond resetting state.
+        let this = Self::this_mut();
+        this.modify_object.clear();
+        this.modify_rowid_col.clear();
         Ok(())
     }
 }
 
+// Mutation names keyed by the table's `object` option. `archive` is only populated for
+// entities Linear lets you soft-delete; everything else only has the hard `delete`
+// mutation. `project_issues` rows are still `Issue`s under the hood (the table just
+// scopes reads to one project via its nested connection), so it shares the `issue*`
+// mutations with `issues`; writes through it are not restricted to that project.
+struct MutationSet {
+    create: &'static str,
+    update: &'static str,
+    delete: &'static str,
+    archive: Option<&'static str>,
+}
+
+fn mutation_names(object: &str) -> Result<MutationSet, FdwError> {
+    match object {
+        "issues" | "project_issues" => Ok(MutationSet {
+            create: "issueCreate",
+            update: "issueUpdate",
+            delete: "issueDelete",
+            archive: Some("issueArchive"),
+        }),
+        "teams" => Ok(MutationSet {
+            create: "teamCreate",
+            update: "teamUpdate",
+            delete: "teamDelete",
+            archive: None,
+        }),
+        "projects" => Ok(MutationSet {
+            create: "projectCreate",
+            update: "projectUpdate",
+            delete: "projectDelete",
+            archive: None,
+        }),
+        _ => Err(format!("object '{}' does not support insert/update/delete", object)),
+    }
+}
+
+// Columns that are server-computed and must never be sent as `*CreateInput`/`*UpdateInput`
+// fields, plus the one column (`state`) whose writable counterpart is a different column
+// (`state_id` -> `stateId`) rather than itself. Returns `None` to mean "don't send this
+// column at all"; `Some` gives the camelCase input field name to send it under.
+fn mutation_input_field(col_name: &str) -> Option<String> {
+    const READ_ONLY_COLUMNS: &[&str] = &["url", "number", "created_at", "updated_at"];
+    if READ_ONLY_COLUMNS.contains(&col_name) || col_name == "state" {
+        return None;
+    }
+
+    fn snake_to_camel(s: &str) -> String {
+        let mut result = String::new();
+        let mut uppercase = false;
+        for c in s.chars() {
+            if c == '_' {
+                uppercase = true;
+            } else if uppercase {
+                result.push(c.to_ascii_uppercase());
+                uppercase = false;
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    }
+
+    Some(snake_to_camel(col_name))
+}
+
 bindings::export!(LinearFdw with_types_in bindings);
\ No newline at end of file